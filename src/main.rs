@@ -1,12 +1,22 @@
 #[macro_use]
 extern crate horrorshow;
 
-use guppy::graph::{DependencyDirection, PackageGraph, PackageMetadata};
+use guppy::graph::feature::{FeatureId, FeatureLabel};
+use guppy::graph::{DependencyDirection, PackageGraph, PackageLink, PackageMetadata, PackageSet};
+use guppy::platform::{EnabledTernary, Platform, TargetFeatures};
 use guppy::MetadataCommand;
+use guppy::PackageId;
 use horrorshow::helper::doctype;
 use serde::Serialize;
-use std::{collections::HashMap, error::Error, iter, path::PathBuf, str::FromStr};
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fs, iter,
+    path::PathBuf,
+    str::FromStr,
+};
 use structopt::StructOpt;
+use toml_edit::Document;
 
 fn main() -> Result<(), Box<dyn Error>> {
     let opt = Opt::from_args();
@@ -17,7 +27,26 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
     let package_graph = PackageGraph::from_command(&mut cmd)?;
 
-    let package_id = {
+    // either a single package id matched by substring (the classic behaviour), or every package
+    // whose name is `opt.package_id` and whose version satisfies `opt.version_req` -- e.g.
+    // `-p syn --version-req '<2.0'` selects every still-present 1.x `syn` in the graph.
+    let package_ids: Vec<_> = if let Some(version_req) = &opt.version_req {
+        let req = guppy::semver::VersionReq::parse(version_req)?;
+        let matches: Vec<_> = package_graph
+            .packages()
+            .filter(|meta| meta.name() == opt.package_id && req.matches(meta.version()))
+            .map(|meta| meta.id())
+            .collect();
+
+        if matches.is_empty() {
+            return Err(format!(
+                "no package named `{}` has a version matching `{}`",
+                &opt.package_id, version_req
+            )
+            .into());
+        }
+        matches
+    } else {
         let mut candidates = Vec::new();
 
         for id in package_graph.package_ids() {
@@ -27,63 +56,108 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
 
         if candidates.len() == 1 {
-            Ok(candidates[0])
+            candidates
         } else {
             for id in candidates.iter() {
                 eprintln!("\t - {}", id.repr())
             }
-            Err(format!(
+            return Err(format!(
                 "package-id substring should match exactly one package id, {}",
                 &opt.package_id
-            ))
+            )
+            .into());
         }
-    }?;
+    };
 
+    let platform = opt
+        .target
+        .as_ref()
+        .map(|triple| Platform::new(triple, TargetFeatures::Unknown))
+        .transpose()?;
 
-    let package_set = package_graph
-        .query_reverse(iter::once(package_id))?
-        .resolve_with_fn(|_, link| !opt.skip.iter().any(|s| link.to().id().repr().contains(s)));
+    let mut target_dependency = Vec::new();
+    let mut frontier: HashMap<String, Vec<String>> = HashMap::new();
+    let mut feature_frontier: HashMap<String, Vec<FrontierLink>> = HashMap::new();
+    let mut paths: HashMap<String, Vec<Vec<String>>> = HashMap::new();
 
-    if opt.debug {
-        eprintln!("workspace frontier for dependencies on {}:", &package_id);
-    };
+    for &package_id in &package_ids {
+        let package_set = package_graph.query_reverse(iter::once(package_id))?.resolve_with_fn(
+            |_, link| {
+                !opt.skip.iter().any(|s| link.to().id().repr().contains(s))
+                    && link_enabled(&link, &opt.kind, platform.as_ref())
+            },
+        );
 
-    let mut frontier = HashMap::new();
+        let this_target = {
+            let meta = package_graph.metadata(package_id)?;
+            format!("{} {}", meta.name(), meta.version())
+        };
 
-    // reverse deps
-    for link in package_set.links(DependencyDirection::Reverse) {
-        // != implements logical xor
-        if link.to().in_workspace() != link.from().in_workspace() {
-            let dependency_source = display_name(link.from());
+        if opt.debug {
+            eprintln!("workspace frontier for dependencies on {}:", &this_target);
+        };
 
-            if opt.debug {
-                let typ = if link.to().id() == package_id {
-                    "direct"
+        // reverse deps
+        for link in package_set.links(DependencyDirection::Reverse) {
+            // != implements logical xor
+            if link.to().in_workspace() != link.from().in_workspace() {
+                let dependency_source = display_name(link.from());
+
+                if opt.debug {
+                    let typ = if link.to().id() == package_id {
+                        "direct"
+                    } else {
+                        "indirect"
+                    };
+                    eprintln!("\t*{}: {} -> {}", typ, &dependency_source, link.to().name());
+                };
+
+                let entry = frontier.entry(dependency_source).or_insert_with(Vec::new);
+                let dep = format!("{} {}", link.to().name(), link.to().version());
+                // only disambiguate by target version once more than one target is in play, to
+                // keep single-target output identical to before `--version-req` existed
+                let dep = if package_ids.len() > 1 {
+                    format!("{} (target {})", dep, &this_target)
                 } else {
-                    "indirect"
+                    dep
                 };
-                eprintln!("\t*{}: {} -> {}", typ, &dependency_source, link.to().name());
-            };
+                entry.push(dep);
+            }
+        }
 
-            let entry = frontier.entry(dependency_source).or_insert_with(Vec::new);
-            entry.push(format!("{} {}", link.to().name(), link.to().version()))
+        if opt.features {
+            for (source, links) in
+                compute_feature_frontier(&package_graph, package_id, &opt, platform.as_ref())?
+            {
+                feature_frontier.entry(source).or_insert_with(Vec::new).extend(links);
+            }
         }
-    }
 
-    let target_dependency = {
-        let meta = package_graph.metadata(package_id)?;
-        format!("{} {}", meta.name(), meta.version())
-    };
+        if opt.paths {
+            for (source, chains) in compute_paths(&package_graph, &package_set, package_id) {
+                paths.entry(source).or_insert_with(Vec::new).extend(chains);
+            }
+        }
+
+        target_dependency.push(this_target);
+    }
 
     let out = Output {
         target_dependency,
         frontier,
+        paths: if opt.paths { Some(paths) } else { None },
+        feature_frontier: if opt.features { Some(feature_frontier) } else { None },
     };
 
+    if opt.fix {
+        fix_manifests(&package_graph, &out, opt.write)?;
+    }
+
     let out = match opt.format {
         OutputFmt::JSON => serde_json::to_string(&out)?,
         OutputFmt::TOML => toml::to_string(&out)?,
         OutputFmt::HTML => out.to_html(),
+        OutputFmt::Dot => out.to_dot(),
     };
 
     println!("{}", out);
@@ -96,20 +170,418 @@ fn display_name(m: PackageMetadata) -> String {
     m.name().replace("_", "-")
 }
 
+/// Which `Cargo.toml` dependency table a link was declared in. Mirrors the `normal()`/`dev()`/
+/// `build()` accessors guppy exposes on `PackageLink`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DepKind {
+    Normal,
+    Dev,
+    Build,
+}
+
+impl FromStr for DepKind {
+    type Err = &'static str;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "normal" => Ok(Self::Normal),
+            "dev" => Ok(Self::Dev),
+            "build" => Ok(Self::Build),
+            _ => Err("must be one of [normal, dev, build]"),
+        }
+    }
+}
+
+/// True if `link` is present as at least one of `kinds` (all three if empty) and, when a
+/// `platform` is given, enabled on it for that kind. `EnabledTernary::Disabled` is the only
+/// value that rules a kind out entirely, so anything `Enabled` or `Unknown` still counts.
+/// This is how `--kind normal` excludes a link that exists only as a dev-dependency, and how
+/// `--target` drops one that's cfg'd out entirely on the requested triple.
+fn link_enabled(link: &PackageLink, kinds: &[DepKind], platform: Option<&Platform>) -> bool {
+    let kinds: &[DepKind] = if kinds.is_empty() {
+        &[DepKind::Normal, DepKind::Dev, DepKind::Build]
+    } else {
+        kinds
+    };
+
+    kinds.iter().any(|kind| {
+        let req = match kind {
+            DepKind::Normal => link.normal(),
+            DepKind::Dev => link.dev(),
+            DepKind::Build => link.build(),
+        };
+        req.is_present()
+            && platform.map_or(true, |platform| {
+                req.status().enabled_on(platform) != EnabledTernary::Disabled
+            })
+    })
+}
+
+/// For every frontier entry, open the owning workspace member's Cargo.toml with `toml_edit`
+/// (so formatting/comments survive) and propose an edit that severs the offending dependency.
+/// Every matching dep not declared `optional = true` gets `default-features = false`; whether it
+/// reaches the target only through default features is not checked, so that edit does not by
+/// itself guarantee the target is dropped. Anything declared `optional = true` -- i.e. genuinely only reachable via another feature's
+/// `dep:name` activation, exactly the case `--features`/`feature_frontier` (chunk0-2) exists to
+/// surface -- gets a suggestion printed instead, since flipping a flag on it could silently
+/// break whichever caller opted into that feature on purpose.
+fn fix_manifests(package_graph: &PackageGraph, out: &Output, write: bool) -> Result<(), Box<dyn Error>> {
+    for (source, deps) in out.frontier.iter() {
+        let meta = match package_graph
+            .packages()
+            .find(|m| m.in_workspace() && display_name(m.clone()) == *source)
+        {
+            Some(m) => m,
+            None => continue,
+        };
+
+        let manifest_path = meta.manifest_path();
+        let raw = fs::read_to_string(manifest_path)?;
+        let mut doc = raw.parse::<Document>()?;
+        let mut dirty = false;
+
+        for dep in deps {
+            let dep_name = dep.split_whitespace().next().unwrap_or(dep);
+            match sever_dependency(&mut doc, dep_name) {
+                SeverOutcome::Severed(table_label) => {
+                    eprintln!(
+                        "--- a/{}\n+++ b/{} [{}.{}] default-features = false  # drops transitive dep on `{}`",
+                        manifest_path, manifest_path, table_label, dep_name, out.target_dependency.join(", ")
+                    );
+                    dirty = true;
+                }
+                SeverOutcome::Optional(table_label) => {
+                    eprintln!(
+                        "# {}: `{}` ([{}.{}]) is `optional = true` and only reaches `{}` via another feature's activation; no safe automatic edit, consider a [patch] stanza",
+                        source, dep_name, table_label, dep_name, out.target_dependency.join(", ")
+                    );
+                }
+                SeverOutcome::NotFound => {
+                    eprintln!(
+                        "# {}: `{}` not found in any dependencies/dev-dependencies/build-dependencies table (including target-specific ones); no safe automatic edit",
+                        source, dep_name
+                    );
+                }
+            }
+        }
+
+        if dirty {
+            if write {
+                fs::write(manifest_path, doc.to_string())?;
+            } else {
+                eprintln!("# (dry run: pass --fix --write to apply the edit above for {})", manifest_path);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Result of attempting to sever a single dependency edge from a manifest.
+enum SeverOutcome {
+    /// edited; the `[table]` (e.g. `dependencies` or `target."cfg(windows)".build-dependencies`)
+    /// that got `default-features = false`
+    Severed(String),
+    /// found but `optional = true`, so left untouched; same table-label format as `Severed`
+    Optional(String),
+    /// `dep_name` isn't declared in any table this pass looks at
+    NotFound,
+}
+
+/// Find `dep_name` (matched against the resolved crate name, not the TOML key, so renamed deps
+/// via `package = "..."` are handled) in `[dependencies]`/`[dev-dependencies]`/
+/// `[build-dependencies]` at the document root, and in the same three tables nested under every
+/// `[target.'cfg(...)'.*]` section. Plain string deps (`dep = "1.0"`, the common case) are
+/// converted to an inline table first so `default-features = false` has somewhere to live; a dep
+/// declared `optional = true` is reported as `Optional` rather than edited, since it can only be
+/// pulled in by another feature's `dep:name` activation and severing it here would be a guess.
+fn sever_dependency(doc: &mut Document, dep_name: &str) -> SeverOutcome {
+    let platform_keys: Vec<String> = doc
+        .as_table()
+        .get("target")
+        .and_then(|item| item.as_table())
+        .map(|table| table.iter().map(|(key, _)| key.to_owned()).collect())
+        .unwrap_or_default();
+
+    let table_kinds = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+    for platform in iter::once(None).chain(platform_keys.into_iter().map(Some)) {
+        for table_kind in table_kinds {
+            let table = match &platform {
+                None => doc.as_table_mut().entry(table_kind).as_table_mut(),
+                Some(platform) => doc
+                    .as_table_mut()
+                    .entry("target")
+                    .as_table_mut()
+                    .and_then(|target| target.entry(platform).as_table_mut())
+                    .and_then(|cfg| cfg.entry(table_kind).as_table_mut()),
+            };
+            let table = match table {
+                Some(table) => table,
+                None => continue,
+            };
+
+            let dep_key = table
+                .iter()
+                .find(|(key, item)| {
+                    let resolved = item
+                        .as_table_like()
+                        .and_then(|t| t.get("package"))
+                        .and_then(|p| p.as_str())
+                        .unwrap_or(key);
+                    resolved == dep_name
+                })
+                .map(|(key, _)| key.to_owned());
+
+            let dep_key = match dep_key {
+                Some(dep_key) => dep_key,
+                None => continue,
+            };
+
+            let table_label = match &platform {
+                None => table_kind.to_string(),
+                Some(platform) => format!("target.\"{}\".{}", platform, table_kind),
+            };
+
+            let is_optional = table
+                .get(&dep_key)
+                .and_then(|item| item.as_table_like())
+                .and_then(|t| t.get("optional"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+
+            if is_optional {
+                return SeverOutcome::Optional(table_label);
+            }
+
+            if let Some(version) = table.get(&dep_key).and_then(|item| item.as_str()) {
+                let version = version.to_owned();
+                let mut inline = toml_edit::InlineTable::new();
+                inline.insert("version", version.into());
+                inline.insert("default-features", false.into());
+                table.insert(&dep_key, toml_edit::Item::Value(toml_edit::Value::InlineTable(inline)));
+                return SeverOutcome::Severed(table_label);
+            }
+
+            if let Some(item) = table.get_mut(&dep_key) {
+                if let Some(tbl) = item.as_table_like_mut() {
+                    tbl.insert("default-features", toml_edit::value(false));
+                    return SeverOutcome::Severed(table_label);
+                }
+            }
+        }
+    }
+    SeverOutcome::NotFound
+}
+
+/// Feature-graph-aware version of the frontier: computed over `FeatureGraph` nodes instead of
+/// plain packages, so each entry can say *which* feature activation on the introducing edge is
+/// responsible, rather than just naming the dependency.
+#[derive(Serialize)]
+struct FrontierLink {
+    dependency: String,
+    /// feature label(s) on the introducing edge, as bare names without a package prefix: a
+    /// named feature such as `derive` or `default`, `dep:name` for an optional dependency, or
+    /// `<unconditional>` for an edge present regardless of feature selection
+    features: Vec<String>,
+}
+
+/// Walk the reverse feature graph rooted at every feature of `package_id` (its base feature plus
+/// all named features, since enabling any of them can pull in the target) and record, for each
+/// workspace-crossing link, which `FeatureLabel`s on that edge are responsible. This is strictly
+/// more granular than `frontier`: a package can show up here only under some of its features.
+fn compute_feature_frontier(
+    package_graph: &PackageGraph,
+    package_id: &guppy::PackageId,
+    opt: &Opt,
+    platform: Option<&Platform>,
+) -> Result<HashMap<String, Vec<FrontierLink>>, Box<dyn Error>> {
+    let feature_graph = package_graph.feature_graph();
+    let meta = package_graph.metadata(package_id)?;
+
+    let target_features: Vec<_> = iter::once(FeatureId::base(package_id))
+        .chain(meta.named_features().map(|f| FeatureId::new(package_id, f)))
+        .collect();
+
+    let feature_set = feature_graph
+        .query_reverse(target_features)?
+        .resolve_with_fn(|_, link| {
+            !opt.skip
+                .iter()
+                .any(|s| link.to().package_id().repr().contains(s))
+                // a feature edge within the same package has no backing `PackageLink` (and no
+                // notion of dependency kind/platform), so only cross-package edges get filtered
+                && link
+                    .package_link()
+                    .map_or(true, |pkg_link| link_enabled(&pkg_link, &opt.kind, platform))
+        });
+
+    let mut frontier = HashMap::new();
+
+    for link in feature_set.links(DependencyDirection::Reverse) {
+        let from_pkg = link.from().package();
+        let to_pkg = link.to().package();
+
+        if to_pkg.in_workspace() != from_pkg.in_workspace() {
+            let dependency_source = display_name(from_pkg);
+
+            let features: Vec<String> = link
+                .status()
+                .feature_labels()
+                .map(|label| match label {
+                    // `Base` is the unconditional edge present regardless of feature selection --
+                    // distinct from the real `default` named feature, which *can* be turned off
+                    FeatureLabel::Base => "<unconditional>".to_string(),
+                    FeatureLabel::Named(name) => name.to_string(),
+                    FeatureLabel::OptionalDependency(name) => format!("dep:{}", name),
+                })
+                .collect();
+
+            if opt.debug {
+                eprintln!(
+                    "\t*feature: {} -> {} (via {:?})",
+                    &dependency_source,
+                    to_pkg.name(),
+                    &features
+                );
+            }
+
+            let entry = frontier
+                .entry(dependency_source)
+                .or_insert_with(Vec::new);
+            entry.push(FrontierLink {
+                dependency: format!("{} {}", to_pkg.name(), to_pkg.version()),
+                features,
+            });
+        }
+    }
+
+    Ok(frontier)
+}
+
+/// For each workspace member that reaches the target (not only the frontier source packages),
+/// find a concrete dependency chain down to the target via a BFS over reverse links that stays within `package_set` (the already-resolved reverse-dep
+/// set). We first BFS outward from `package_id` following links backwards (to -> from) to get
+/// the shortest distance-to-target for every reachable node, then for each workspace member walk
+/// *forward* (workspace-crate -> ... -> target), at each step greedily taking the lexicographically
+/// smallest neighbor that still sits on a shortest path. Chains are compared/reported in that same
+/// workspace-to-target order, so building left-to-right is what makes the greedy choice at each
+/// step equivalent to picking the lexicographically smallest whole chain.
+fn compute_paths(
+    package_graph: &PackageGraph,
+    package_set: &PackageSet,
+    package_id: &PackageId,
+) -> HashMap<String, Vec<Vec<String>>> {
+    let mut reverse_adj: HashMap<&PackageId, Vec<&PackageId>> = HashMap::new();
+    let mut forward_adj: HashMap<&PackageId, Vec<&PackageId>> = HashMap::new();
+    for link in package_set.links(DependencyDirection::Reverse) {
+        reverse_adj
+            .entry(link.to().id())
+            .or_insert_with(Vec::new)
+            .push(link.from().id());
+        forward_adj
+            .entry(link.from().id())
+            .or_insert_with(Vec::new)
+            .push(link.to().id());
+    }
+
+    // distance (in hops) from each reachable node back to `package_id`
+    let mut dist: HashMap<&PackageId, usize> = HashMap::new();
+    dist.insert(package_id, 0);
+    let mut queue = VecDeque::new();
+    queue.push_back(package_id);
+    while let Some(node) = queue.pop_front() {
+        if let Some(froms) = reverse_adj.get(node) {
+            for &from in froms {
+                if !dist.contains_key(from) {
+                    dist.insert(from, dist[node] + 1);
+                    queue.push_back(from);
+                }
+            }
+        }
+    }
+
+    let target_label = package_graph
+        .metadata(package_id)
+        .map(|m| format!("{} {}", m.name(), m.version()))
+        .unwrap_or_default();
+
+    let mut chains: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+
+    for (&node, &distance) in dist.iter() {
+        if node == package_id {
+            continue;
+        }
+        let meta = match package_graph.metadata(node) {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
+        if !meta.in_workspace() {
+            continue;
+        }
+
+        let mut chain = vec![display_name(meta)];
+        let mut cur = node;
+        let mut remaining = distance;
+        while cur != package_id {
+            let next = forward_adj
+                .get(cur)
+                .into_iter()
+                .flatten()
+                .filter(|&&n| dist.get(n).copied() == Some(remaining - 1))
+                .min_by_key(|&&n| {
+                    package_graph
+                        .metadata(n)
+                        .map(|m| display_name(m))
+                        .unwrap_or_default()
+                });
+
+            let next = match next {
+                Some(&n) => n,
+                None => break,
+            };
+
+            remaining -= 1;
+            cur = next;
+            chain.push(if cur == package_id {
+                target_label.clone()
+            } else {
+                package_graph
+                    .metadata(cur)
+                    .map(|m| display_name(m))
+                    .unwrap_or_default()
+            });
+        }
+
+        chains.entry(chain[0].clone()).or_insert_with(Vec::new).push(chain);
+    }
+
+    chains
+}
+
 // TODO/FIXME: this is an output format, but still: less 'String' types
 #[derive(Serialize)]
 struct Output {
-    // dependency for which a reverse transitive dependency graph was computed
-    target_dependency: String,
+    /// the matched target dependency version(s); more than one when `--version-req` matches
+    /// several versions of the same crate still present in the graph
+    target_dependency: Vec<String>,
     /// Map of package name to list of dependencies via which a transitive dep on 'package_id' is introduced to said package
     frontier: HashMap<String, Vec<String>>,
+    /// Feature-aware frontier, populated only when `--features` is passed; see `FrontierLink`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    feature_frontier: Option<HashMap<String, Vec<FrontierLink>>>,
+    /// Full dependency chains from each workspace member that reaches the target down to it,
+    /// keyed by that member, populated only when `--paths` is passed. Each chain runs
+    /// `[workspace-crate, ..., target]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    paths: Option<HashMap<String, Vec<Vec<String>>>>,
 }
 
 impl Output {
     fn to_html(self) -> String {
+        let target_dependency = self.target_dependency.join(", ");
         let my_title: String = format!(
             "workspace frontier for transitive dependencies on {}",
-            self.target_dependency
+            &target_dependency
         );
         format!(
             "{}",
@@ -127,7 +599,7 @@ impl Output {
                         ol(id="main") {
                             @ for (k,v) in self.frontier.iter() {
                                 li(class="item") {
-                                    : format_args!("package `{}` introduces transitive dependencies on `{}` via:", k, &self.target_dependency);
+                                    : format_args!("package `{}` introduces transitive dependencies on `{}` via:", k, &target_dependency);
                                     ol(class="nested") {
                                         @ for dep in v.iter() {
                                             li(class="nested-item") {
@@ -143,6 +615,62 @@ impl Output {
             }
         )
     }
+
+    /// Render the frontier as a Graphviz DOT digraph: workspace crates clustered and colored
+    /// distinctly from the rest of the graph (mirroring the `in_workspace()` XOR check used to
+    /// build the frontier in the first place), the target dependency as a highlighted sink node,
+    /// and edges labeled with the introducing dependency + version. Pipe the output into
+    /// `dot -Tsvg` for a visual map; the HTML output is a flat list and can't show this.
+    fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph frontier {\n");
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [shape=box, style=filled, fillcolor=white];\n\n");
+
+        dot.push_str("  subgraph cluster_workspace {\n");
+        dot.push_str("    label=\"workspace\";\n");
+        dot.push_str("    style=dashed;\n");
+        for source in self.frontier.keys() {
+            dot.push_str(&format!(
+                "    \"{}\" [fillcolor=lightblue];\n",
+                escape_dot(source)
+            ));
+        }
+        dot.push_str("  }\n\n");
+
+        for target in &self.target_dependency {
+            dot.push_str(&format!(
+                "  \"{}\" [shape=doublecircle, fillcolor=orange];\n",
+                escape_dot(target)
+            ));
+        }
+        dot.push('\n');
+
+        for (source, deps) in self.frontier.iter() {
+            for dep in deps {
+                // when `--version-req` matched more than one target version, `dep` carries a
+                // trailing "(target X)" annotation (see main()); strip it for the node id so all
+                // targets' edges to the same introducing dependency share one node (a direct
+                // dependency's node is its `target_dependency` sink node), and keep the full
+                // string only as the edge label
+                let node = dep.split(" (target ").next().unwrap_or(dep);
+                dot.push_str(&format!(
+                    "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                    escape_dot(source),
+                    escape_dot(node),
+                    escape_dot(dep)
+                ));
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// DOT quoted identifiers only need to escape embedded double quotes and backslashes.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
 #[derive(Debug, StructOpt)]
@@ -159,10 +687,17 @@ struct Opt {
     #[structopt(parse(from_os_str))]
     workspace: Option<PathBuf>,
 
-    /// substring of package id to run on. must be unique in the workspace's package graph.
+    /// substring of package id to run on (must be unique in the workspace's package graph), or,
+    /// when combined with `--version-req`, an exact crate name to match against that range
     #[structopt(short)]
     package_id: String,
 
+    /// match every version of `-p`'s crate name satisfying this semver range (e.g. `<2.0`)
+    /// instead of requiring `-p` to uniquely identify a single package id. Useful for auditing
+    /// "who is still pulling in the old major version".
+    #[structopt(long = "version-req")]
+    version_req: Option<String>,
+
     /// links to skip when resolving reverse transitive dependencies
     #[structopt(short, long)]
     skip: Vec<String>,
@@ -170,6 +705,37 @@ struct Opt {
     /// output format. defaults to toml
     #[structopt(short, long, default_value = "toml")]
     format: OutputFmt,
+
+    /// compute and print suggested manifest edits that would sever the frontier links found.
+    /// prints a diff-style summary to stderr; combine with --write to apply it.
+    #[structopt(long)]
+    fix: bool,
+
+    /// actually write the edits proposed by --fix. has no effect without --fix.
+    #[structopt(long)]
+    write: bool,
+
+    /// also compute the frontier over guppy's feature graph, alongside the package graph one, recording
+    /// which feature activation on each introducing edge is responsible (see `feature_frontier`
+    /// in the output)
+    #[structopt(long)]
+    features: bool,
+
+    /// restrict resolution to links enabled on this target triple (e.g. `x86_64-unknown-linux-gnu`).
+    /// links that are never enabled on the given platform (cfg'd out for all of normal/build/dev)
+    /// are dropped before the frontier is computed.
+    #[structopt(long)]
+    target: Option<String>,
+
+    /// report a full dependency chain (workspace crate -> ... -> target) for each workspace
+    /// crate that reaches the target, not just the immediate introducing dependency
+    #[structopt(long)]
+    paths: bool,
+
+    /// restrict to these dependency kinds (repeatable: normal, dev, build). defaults to all
+    /// three if not given, e.g. `--kind normal` answers "does this end up in my shipping binary?"
+    #[structopt(long = "kind")]
+    kind: Vec<DepKind>,
 }
 
 #[derive(Debug)]
@@ -177,6 +743,7 @@ enum OutputFmt {
     TOML,
     JSON,
     HTML,
+    Dot,
 }
 
 impl FromStr for OutputFmt {
@@ -186,7 +753,8 @@ impl FromStr for OutputFmt {
             "toml" => Ok(Self::TOML),
             "json" => Ok(Self::JSON),
             "html" => Ok(Self::HTML),
-            _ => Err("must be one of [toml, json]"),
+            "dot" => Ok(Self::Dot),
+            _ => Err("must be one of [toml, json, html, dot]"),
         }
     }
 }